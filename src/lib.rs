@@ -1,32 +1,215 @@
-use std::ops::{Add, Sub};
+use std::ops::{Add, Sub, Neg, Mul};
 use std::fmt;
 
+mod traversal;
+pub use traversal::walk;
+
+///A signed displacement between two points, as opposed to `Point` which is a position. Use
+///`Point - Point` to get one, and `Point + Vector` / `Point - Vector` to apply one.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct Vector(isize, isize);
+
+impl Vector {
+    ///Creates a new Vector with the given x and y displacement.
+    pub fn new(x: isize, y: isize) -> Self {
+        Vector(x, y)
+    }
+
+    ///Returns the x and y displacement as a tuple.
+    pub fn get(self) -> (isize, isize) {
+        (self.0, self.1)
+    }
+
+    ///Returns the dot product of `self` and `other`.
+    pub fn dot(self, other: Self) -> isize {
+        self.0 * other.0 + self.1 * other.1
+    }
+
+    ///Returns a Vector with each component reduced to its sign (-1, 0, or 1).
+    pub fn signum(self) -> Self {
+        Vector(self.0.signum(), self.1.signum())
+    }
+
+    ///Returns a Vector with each component's absolute value.
+    pub fn abs(self) -> Self {
+        Vector(self.0.abs(), self.1.abs())
+    }
+
+    ///Returns the Manhattan (taxicab) norm: `|x| + |y|`.
+    pub fn manhattan(self) -> isize {
+        self.0.abs() + self.1.abs()
+    }
+
+    ///Returns the Chebyshev (chessboard) norm: `max(|x|, |y|)`.
+    pub fn chebyshev(self) -> isize {
+        self.0.abs().max(self.1.abs())
+    }
+
+    ///Returns the squared Euclidean norm: `x*x + y*y`. Avoids a sqrt for callers only comparing distances.
+    pub fn euclidean_squared(self) -> isize {
+        self.0 * self.0 + self.1 * self.1
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vector(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vector(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vector(-self.0, -self.1)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Self;
+
+    fn mul(self, scalar: isize) -> Self {
+        Vector(self.0 * scalar, self.1 * scalar)
+    }
+}
+
+///A compass direction a `Point` can step in, including the four diagonals.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    ///Returns all eight directions in clockwise order starting from `North`.
+    pub fn all() -> [Direction; 8] {
+        [
+            Direction::North,
+            Direction::NorthEast,
+            Direction::East,
+            Direction::SouthEast,
+            Direction::South,
+            Direction::SouthWest,
+            Direction::West,
+            Direction::NorthWest,
+        ]
+    }
+
+    ///Rotates 90 degrees clockwise, e.g. `North` -> `East`.
+    pub fn turn_right(self) -> Self {
+        Self::all()[(Self::index(self) + 2) % 8]
+    }
+
+    ///Rotates 90 degrees counter-clockwise, e.g. `North` -> `West`.
+    pub fn turn_left(self) -> Self {
+        Self::all()[(Self::index(self) + 6) % 8]
+    }
+
+    ///Returns the direction facing the opposite way, e.g. `North` -> `South`.
+    pub fn opposite(self) -> Self {
+        Self::all()[(Self::index(self) + 4) % 8]
+    }
+
+    fn index(self) -> usize {
+        Self::all().iter().position(|&d| d == self).unwrap()
+    }
+}
+
+impl From<Direction> for (i64, i64) {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::North => (0, 1),
+            Direction::NorthEast => (1, 1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, -1),
+            Direction::South => (0, -1),
+            Direction::SouthWest => (-1, -1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, 1),
+        }
+    }
+}
+
+///The arithmetic `Point<T>` needs from its backing coordinate type. Integers get real
+///overflow/underflow checking (so e.g. `usize` correctly refuses to go below zero); floats have
+///no such concept, so their `checked_add`/`checked_sub` simply always succeed.
+pub trait GridCoord: Copy + PartialOrd {
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_grid_coord_int {
+    ($($t:ty),*) => {
+        $(impl GridCoord for $t {
+            fn checked_add(self, other: Self) -> Option<Self> { <$t>::checked_add(self, other) }
+            fn checked_sub(self, other: Self) -> Option<Self> { <$t>::checked_sub(self, other) }
+        })*
+    };
+}
+impl_grid_coord_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_grid_coord_float {
+    ($($t:ty),*) => {
+        $(impl GridCoord for $t {
+            fn checked_add(self, other: Self) -> Option<Self> { Some(self + other) }
+            fn checked_sub(self, other: Self) -> Option<Self> { Some(self - other) }
+        })*
+    };
+}
+impl_grid_coord_float!(f32, f64);
+
 ///Builder for points that saves me from having to define the bounds for every point.
-pub struct PointBuilder(usize, usize);
+pub struct PointBuilder<T = usize>(T, T);
 
-impl PointBuilder {
+impl<T: GridCoord> PointBuilder<T> {
     ///Creates a new PointBuilder with bounds x and y for bounds.
-    pub fn new(x: usize, y: usize) -> Self {
+    pub fn new(x: T, y: T) -> Self {
         PointBuilder(x, y)
     }
 
     ///Builds a new point at the coordinates x and y with bounds set when PointBuilder is defined.
-    pub fn build(&self, x: usize, y: usize) -> Point {
+    pub fn build(&self, x: T, y: T) -> Point<T> {
         Point(x, y, self.0, self.1)
     }
 }
 
-///Coordinate struct where the first two fields are x and y, and the second two fields are x_bound and y_bound.
+#[cfg(feature = "rand")]
+impl PointBuilder<usize> {
+    ///Builds a new point at a uniformly random in-bounds coordinate, drawing x from `0..x_bound` and y from `0..y_bound`. Panics if either bound is zero.
+    pub fn random<R: rand::Rng>(&self, rng: &mut R) -> Point {
+        Point(rng.gen_range(0..self.0), rng.gen_range(0..self.1), self.0, self.1)
+    }
+}
+
+///Coordinate struct where the first two fields are x and y, and the second two fields are x_bound
+///and y_bound. Generic over the coordinate type `T`; defaults to `usize` so existing code that
+///just writes `Point` keeps compiling unchanged.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
-pub struct Point(usize, usize, usize, usize);
+pub struct Point<T = usize>(T, T, T, T);
 
-impl Point {
+impl<T: GridCoord> Point<T> {
     ///Creates a new PointBuilder with bounds x and y.
-    pub fn builder(x: usize, y: usize) -> PointBuilder {
+    pub fn builder(x: T, y: T) -> PointBuilder<T> {
         PointBuilder::new(x, y)
     }
 
-    ///Performs an addition operation and returns the result as an option. Automatically performs a bounds check based on the bounds given,   
+    ///Performs an addition operation and returns the result as an option. Automatically performs a bounds check based on the bounds given,
     ///and returns None if x or y is outside the bounds. The bounds of the second point don't matter, and can safely be ignored.
     pub fn checked_add(self, other: Self) -> Option<Self> {
         self.0.checked_add(other.0).zip(self.1.checked_add(other.1)).map(|result|{
@@ -39,6 +222,10 @@ impl Point {
     }
 
     ///Performs a subtraction operation and returns the result as an option. Returns `None` if either x or y would go below zero.
+    ///Note this treats `other` as a delta applied component-wise to `self` (like `checked_add`), and so returns `Self` with `self`'s
+    ///bounds preserved. That's a different operation from the `-` operator on two `Point`s, which treats both sides as positions
+    ///and returns the `Vector` displacement between them.
+    ///Going below zero gives `None` only for unsigned `T`: signed `T` yields negative coordinates, and float `T` always succeeds.
     pub fn checked_sub(self, other: Self) -> Option<Self> {
         self.0.checked_sub(other.0).zip(self.1.checked_sub(other.1)).map(|result|{
             let (x, y) = result;
@@ -47,66 +234,304 @@ impl Point {
     }
 
     ///Returns the x and y coordinates as a tuple.
-    pub fn get(self) -> (usize, usize) {
+    pub fn get(self) -> (T, T) {
         (self.0, self.1)
     }
+}
 
-    ///Returns a vector of the points in each cardinal direction. Panics if no direction is in bounds.
-    pub fn check_neighbors(self) -> Vec<Point> {
+impl Point<usize> {
+    ///Returns the full grid extent `self` was built with, as a Rect from `(0, 0)` to `(x_bound - 1, y_bound - 1)`.
+    pub fn bounds_rect(self) -> Rect {
+        Rect::new(Point(0, 0, self.2, self.3), Point(self.2 - 1, self.3 - 1, self.2, self.3))
+    }
+
+    ///Returns a vector of the points in each cardinal direction along with which way each one lies. Panics if no direction is in bounds.
+    pub fn check_neighbors(self) -> Vec<(Direction, Point)> {
         let mut rtn = Vec::with_capacity(4);
-        if let Some(result) = self.north() {rtn.push(result);}
-        if let Some(result) = self.south() {rtn.push(result);}
-        if let Some(result) = self.east() {rtn.push(result);}
-        if let Some(result) = self.west() {rtn.push(result);}
+        for dir in [Direction::North, Direction::South, Direction::East, Direction::West] {
+            if let Some(result) = self.step(dir) {rtn.push((dir, result));}
+        }
         if rtn.is_empty() {panic!("Point {{{}, {}}} has no neigbors in bounds: {{{}, {}}}.", self.0, self.1, self.2, self.3)}
         rtn
     }
 
+    ///Moves one step in the given direction, bounds-checked. Returns `None` if the result would fall outside `0..x_bound` or `0..y_bound`.
+    pub fn step(self, dir: Direction) -> Option<Self> {
+        let (dx, dy): (i64, i64) = dir.into();
+        let x = self.0 as i64 + dx;
+        let y = self.1 as i64 + dy;
+        if x < 0 || y < 0 {
+            return None;
+        }
+        let (x, y) = (x as usize, y as usize);
+        if x < self.2 && y < self.3 {
+            Some(Self(x, y, self.2, self.3))
+        } else {
+            None
+        }
+    }
+
     ///Returns the point to the north of `self`. Returns `None` if its out of bounds.
     pub fn north(self) -> Option<Self> {
-        self.checked_add(Point(0, 1, 0, 0))
+        self.step(Direction::North)
     }
 
     ///Returns the point to the south of `self`. Returns `None` if its out of bounds.
     pub fn south(self) -> Option<Self> {
-        self.checked_sub(Point(0, 1, 0, 0))
+        self.step(Direction::South)
     }
 
     ///Returns the point to the east of `self`. Returns `None` if its out of bounds.
     pub fn east(self) -> Option<Self> {
-        self.checked_add(Point(1, 0, 0, 0))
+        self.step(Direction::East)
     }
 
     ///Returns the point to the west of `self`. Returns `None` if its out of bounds.
     pub fn west(self) -> Option<Self> {
-        self.checked_sub(Point(1, 0, 0, 0))
+        self.step(Direction::West)
     }
 }
 
-impl Add for Point {
-    type Output = Self;
-   
-    fn add(self, other: Self) -> Self {
-        Self(self.0 + other.0, self.1 + other.1, self.2, self.3)
+#[cfg(feature = "rand")]
+impl Point<usize> {
+    ///Returns a uniformly random point within the same bounds as `self`, drawing x from `0..x_bound` and y from `0..y_bound`. Panics if either bound is zero.
+    pub fn random_in_bounds<R: rand::Rng>(&self, rng: &mut R) -> Point {
+        Point(rng.gen_range(0..self.2), rng.gen_range(0..self.3), self.2, self.3)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Option<Point>;
+
+    ///Applies a displacement to a position. Bounds- and overflow-checked; returns `None` if the result falls outside `0..x_bound` or `0..y_bound`.
+    fn add(self, vector: Vector) -> Option<Point> {
+        let x = self.0 as i128 + vector.0 as i128;
+        let y = self.1 as i128 + vector.1 as i128;
+        if x < 0 || y < 0 || x >= self.2 as i128 || y >= self.3 as i128 {
+            return None;
+        }
+        Some(Self(x as usize, y as usize, self.2, self.3))
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Option<Point>;
+
+    ///Applies the opposite of a displacement to a position. Bounds- and overflow-checked, see `Add<Vector>`.
+    fn sub(self, vector: Vector) -> Option<Point> {
+        let x = self.0 as i128 - vector.0 as i128;
+        let y = self.1 as i128 - vector.1 as i128;
+        if x < 0 || y < 0 || x >= self.2 as i128 || y >= self.3 as i128 {
+            return None;
+        }
+        Some(Self(x as usize, y as usize, self.2, self.3))
     }
 }
 
 impl Sub for Point {
-    type Output = Self;
-   
-    fn sub(self, other: Self) -> Self {
-        Self(self.0 - other.0, self.1 - other.1, self.2, self.3)
+    type Output = Vector;
+
+    ///Returns the displacement from `other` to `self`.
+    fn sub(self, other: Self) -> Vector {
+        Vector(self.0 as isize - other.0 as isize, self.1 as isize - other.1 as isize)
     }
 }
 
-impl fmt::Display for Point {
+impl<T: fmt::Display> fmt::Display for Point<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}, {}", self.0, self.1)
     }
 }
 
-impl Default for Point {
+impl<T: Default> Default for Point<T> {
     fn default() -> Self {
-        Point(0, 0, 0, 0)
+        Point(T::default(), T::default(), T::default(), T::default())
+    }
+}
+
+///An axis-aligned sub-region of the grid, from `min` to `max` inclusive.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Rect {
+    min: Point,
+    max: Point,
+}
+
+impl Rect {
+    ///Creates a new Rect spanning `min` to `max` inclusive. `min` is expected to be the bottom-left corner and `max` the top-right; use `from_corners` if the corners aren't already ordered.
+    pub fn new(min: Point, max: Point) -> Self {
+        Rect { min, max }
+    }
+
+    ///Creates a Rect spanning two arbitrary corners, ordering them so `min` <= `max` on both axes.
+    pub fn from_corners(a: Point, b: Point) -> Self {
+        let (min_x, max_x) = if a.0 <= b.0 { (a.0, b.0) } else { (b.0, a.0) };
+        let (min_y, max_y) = if a.1 <= b.1 { (a.1, b.1) } else { (b.1, a.1) };
+        Rect::new(Point(min_x, min_y, a.2, a.3), Point(max_x, max_y, a.2, a.3))
+    }
+
+    ///Returns the width and height of the Rect.
+    pub fn size(&self) -> (usize, usize) {
+        (self.max.0 - self.min.0 + 1, self.max.1 - self.min.1 + 1)
+    }
+
+    ///Returns the point at the center of the Rect, rounding down.
+    pub fn center(&self) -> Point {
+        Point((self.min.0 + self.max.0) / 2, (self.min.1 + self.max.1) / 2, self.min.2, self.min.3)
+    }
+
+    ///Returns whether `p` falls within the Rect, inclusive of its edges.
+    pub fn contains(&self, p: Point) -> bool {
+        p.0 >= self.min.0 && p.0 <= self.max.0 && p.1 >= self.min.1 && p.1 <= self.max.1
+    }
+
+    ///Returns the overlapping region of `self` and `other`, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min_x = self.min.0.max(other.min.0);
+        let min_y = self.min.1.max(other.min.1);
+        let max_x = self.max.0.min(other.max.0);
+        let max_y = self.max.1.min(other.max.1);
+        if min_x > max_x || min_y > max_y {
+            None
+        } else {
+            Some(Rect::new(Point(min_x, min_y, self.min.2, self.min.3), Point(max_x, max_y, self.min.2, self.min.3)))
+        }
+    }
+
+    ///Returns the smallest Rect containing both `self` and `other`.
+    pub fn union(&self, other: &Rect) -> Rect {
+        let min_x = self.min.0.min(other.min.0);
+        let min_y = self.min.1.min(other.min.1);
+        let max_x = self.max.0.max(other.max.0);
+        let max_y = self.max.1.max(other.max.1);
+        Rect::new(Point(min_x, min_y, self.min.2, self.min.3), Point(max_x, max_y, self.min.2, self.min.3))
+    }
+
+    ///Returns every Point in the Rect, in row-major order (left to right, then bottom to top).
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        let (min, max) = (self.min, self.max);
+        (min.1..=max.1).flat_map(move |y| (min.0..=max.0).map(move |x| Point(x, y, min.2, min.3)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn_right_cycles_cardinals() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::East.turn_right(), Direction::South);
+        assert_eq!(Direction::South.turn_right(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+    }
+
+    #[test]
+    fn turn_left_undoes_turn_right() {
+        for dir in Direction::all() {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+        }
+    }
+
+    #[test]
+    fn opposite_is_two_turns() {
+        assert_eq!(Direction::North.opposite(), Direction::South);
+        assert_eq!(Direction::NorthEast.opposite(), Direction::SouthWest);
+        for dir in Direction::all() {
+            assert_eq!(dir.opposite(), dir.turn_right().turn_right());
+        }
+    }
+
+    #[test]
+    fn direction_deltas() {
+        assert_eq!(<(i64, i64)>::from(Direction::North), (0, 1));
+        assert_eq!(<(i64, i64)>::from(Direction::South), (0, -1));
+        assert_eq!(<(i64, i64)>::from(Direction::East), (1, 0));
+        assert_eq!(<(i64, i64)>::from(Direction::West), (-1, 0));
+    }
+
+    #[test]
+    fn step_respects_bounds() {
+        let p = Point::builder(3, 3).build(0, 0);
+        assert_eq!(p.step(Direction::South), None);
+        assert_eq!(p.step(Direction::West), None);
+        assert_eq!(p.step(Direction::North).unwrap().get(), (0, 1));
+    }
+
+    #[test]
+    fn vector_norms() {
+        let v = Vector::new(3, -4);
+        assert_eq!(v.manhattan(), 7);
+        assert_eq!(v.chebyshev(), 4);
+        assert_eq!(v.euclidean_squared(), 25);
+        assert_eq!(v.abs(), Vector::new(3, 4));
+        assert_eq!(v.signum(), Vector::new(1, -1));
+    }
+
+    #[test]
+    fn vector_dot_and_scalar_mul() {
+        let a = Vector::new(1, 2);
+        let b = Vector::new(3, 4);
+        assert_eq!(a.dot(b), 11);
+        assert_eq!(a * 2, Vector::new(2, 4));
+        assert_eq!(-a, Vector::new(-1, -2));
+    }
+
+    #[test]
+    fn point_sub_gives_displacement_vector() {
+        let builder = Point::builder(10, 10);
+        let a = builder.build(5, 5);
+        let b = builder.build(2, 3);
+        assert_eq!(a - b, Vector::new(3, 2));
+    }
+
+    #[test]
+    fn point_add_and_sub_vector_check_bounds() {
+        let p = Point::builder(3, 3).build(1, 1);
+        assert_eq!(p + Vector::new(1, 1), Some(Point::builder(3, 3).build(2, 2)));
+        assert_eq!(p + Vector::new(5, 5), None);
+        assert_eq!(p - Vector::new(5, 5), None);
+    }
+
+    #[test]
+    fn rect_contains_and_size() {
+        let builder = Point::builder(10, 10);
+        let rect = Rect::new(builder.build(1, 1), builder.build(3, 4));
+        assert_eq!(rect.size(), (3, 4));
+        assert!(rect.contains(builder.build(2, 2)));
+        assert!(!rect.contains(builder.build(0, 0)));
+    }
+
+    #[test]
+    fn rect_intersection_and_union() {
+        let builder = Point::builder(10, 10);
+        let a = Rect::new(builder.build(0, 0), builder.build(4, 4));
+        let b = Rect::new(builder.build(2, 2), builder.build(6, 6));
+        assert_eq!(a.intersection(&b), Some(Rect::new(builder.build(2, 2), builder.build(4, 4))));
+        assert_eq!(a.union(&b), Rect::new(builder.build(0, 0), builder.build(6, 6)));
+
+        let c = Rect::new(builder.build(5, 5), builder.build(6, 6));
+        assert_eq!(a.intersection(&c), None);
+    }
+
+    #[test]
+    fn rect_iter_is_row_major() {
+        let builder = Point::builder(10, 10);
+        let rect = Rect::new(builder.build(0, 0), builder.build(1, 1));
+        let points: Vec<_> = rect.iter().map(Point::get).collect();
+        assert_eq!(points, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn bounds_rect_covers_full_grid() {
+        let p = Point::builder(4, 5).build(0, 0);
+        assert_eq!(p.bounds_rect().size(), (4, 5));
+    }
+
+    #[test]
+    fn generic_point_allows_negative_signed_coordinates() {
+        let builder = Point::<i32>::builder(10, 10);
+        let p = builder.build(0, 0);
+        let delta = builder.build(1, 1);
+        assert_eq!(p.checked_sub(delta).unwrap().get(), (-1, -1));
     }
 }
\ No newline at end of file
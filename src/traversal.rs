@@ -0,0 +1,99 @@
+use crate::{Direction, Point};
+use std::collections::{HashSet, VecDeque};
+
+impl Point<usize> {
+    ///Performs a 4-connected breadth-first flood fill from `self`, visiting only points for which `passable` returns true. Returns every reachable point, including `self` if it's passable.
+    pub fn flood_fill<F: Fn(Point) -> bool>(self, passable: F) -> HashSet<Point> {
+        let mut seen = HashSet::new();
+        if !passable(self) {
+            return seen;
+        }
+        seen.insert(self);
+        let mut queue = VecDeque::new();
+        queue.push_back(self);
+        while let Some(current) = queue.pop_front() {
+            for dir in [Direction::North, Direction::South, Direction::East, Direction::West] {
+                if let Some(neighbor) = current.step(dir) {
+                    if passable(neighbor) && seen.insert(neighbor) {
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        seen
+    }
+}
+
+///Advances a beam or agent step by step from `start` heading `dir`. At each state, `next` is called
+///to produce the successor states to continue into; a state is only ever processed once, so a beam
+///that re-enters a point travelling the same direction stops there instead of looping forever.
+///Returns every Point visited along the way.
+pub fn walk(
+    start: Point,
+    dir: Direction,
+    mut next: impl FnMut(Point, Direction) -> Vec<(Point, Direction)>,
+) -> HashSet<Point> {
+    let mut seen_states = HashSet::new();
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    seen_states.insert((start, dir));
+    queue.push_back((start, dir));
+    while let Some((point, dir)) = queue.pop_front() {
+        visited.insert(point);
+        for (next_point, next_dir) in next(point, dir) {
+            if seen_states.insert((next_point, next_dir)) {
+                queue.push_back((next_point, next_dir));
+            }
+        }
+    }
+    visited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flood_fill_single_cell_does_not_panic() {
+        let p = Point::builder(1, 1).build(0, 0);
+        let reached = p.flood_fill(|_| true);
+        assert_eq!(reached, [p].into_iter().collect());
+    }
+
+    #[test]
+    fn flood_fill_stops_at_impassable_cells() {
+        let builder = Point::builder(3, 1);
+        let start = builder.build(0, 0);
+        let reached = start.flood_fill(|p| p.get().0 < 2);
+        assert_eq!(reached, [builder.build(0, 0), builder.build(1, 0)].into_iter().collect());
+    }
+
+    #[test]
+    fn flood_fill_on_impassable_start_is_empty() {
+        let p = Point::builder(3, 3).build(1, 1);
+        assert!(p.flood_fill(|_| false).is_empty());
+    }
+
+    #[test]
+    fn walk_terminates_on_a_repeating_state() {
+        let start = Point::builder(5, 5).build(2, 2);
+        // Always re-emits the same state, which would loop forever if not deduplicated.
+        let visited = walk(start, Direction::East, |p, d| vec![(p, d)]);
+        assert_eq!(visited, [start].into_iter().collect());
+    }
+
+    #[test]
+    fn walk_bouncing_between_two_points_still_terminates() {
+        let a = Point::builder(5, 5).build(2, 2);
+        let b = a.step(Direction::East).unwrap();
+        // Bounces back and forth forever unless the (Point, Direction) state is deduplicated.
+        let visited = walk(a, Direction::East, move |p, d| {
+            if p == a {
+                vec![(b, d.opposite())]
+            } else {
+                vec![(a, d.opposite())]
+            }
+        });
+        assert_eq!(visited, [a, b].into_iter().collect());
+    }
+}